@@ -5,150 +5,578 @@ use sdl2::{
     event::{Event, WindowEvent},
     mouse::{Cursor, MouseButton, SystemCursor},
 };
+use std::collections::HashMap;
 
 use crate::ToEguiKey;
 
-/// The sdl2 platform for egui
-pub struct Platform {
-    // The cursors for the platform
-    cursor: Option<Cursor>,
-    system_cursor: SystemCursor,
-    // The position of the mouse pointer
+/// How much each unit of scroll/pinch delta contributes to a ctrl-scroll or
+/// pinch-to-zoom's exponential zoom factor
+const ZOOM_STEP: f32 = 0.1;
+
+/// Identifies an SDL2 window, as carried on most `sdl2::event::Event` variants
+pub type WindowId = u32;
+
+/// The [`WindowId`] used by the single-window constructors and by the
+/// zero-argument `context`/`end_frame`/`change_target` wrappers
+pub const DEFAULT_WINDOW_ID: WindowId = 0;
+
+/// The result of feeding an sdl2 [`Event`] to [`Platform::handle_event`].
+///
+/// Lets the host application know whether egui consumed the event, so it can
+/// decide whether to also let it fall through to game/application input.
+#[must_use]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EventResponse {
+    /// Whether egui consumed this event, i.e. the event should *not* be
+    /// handled by the application as well.
+    pub consumed: bool,
+    /// Whether this event should cause a repaint.
+    pub repaint: bool,
+}
+
+/// How the platform converts between SDL2's raw pixel coordinates and the
+/// points egui expects everything (layout, input) to be expressed in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DpiScaling {
+    /// Use a 1:1 scale. There's no live window or video subsystem available
+    /// at this point to query a real DPI/drawable-size ratio from (SDL2
+    /// only allows one live `Sdl` context, and the host already holds it to
+    /// drive its event loop) — use [`Platform::from_window`] instead if you
+    /// want the scale auto-detected from an actual window.
+    Default,
+    /// Use a fixed, explicit scale factor.
+    Custom(f32),
+}
+
+impl Default for DpiScaling {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
+/// Configuration for [`Platform::with_descriptor`], letting custom fonts and
+/// a custom style be installed on the default window before the first frame
+/// is drawn, instead of having to reach into `egui_ctx` after construction.
+pub struct PlatformDescriptor {
+    /// The initial screen rect, in raw pixels
+    pub screen_rect: egui::Rect,
+    /// The initial scale factor, i.e. how many raw pixels make up one point
+    pub scale_factor: f32,
+    /// Custom fonts to install
+    pub font_definitions: egui::FontDefinitions,
+    /// The initial style
+    pub style: egui::Style,
+}
+
+impl Default for PlatformDescriptor {
+    fn default() -> Self {
+        Self {
+            screen_rect: egui::Rect::from_min_size(egui::Pos2::ZERO, egui::Vec2::ZERO),
+            scale_factor: 1.0,
+            font_definitions: egui::FontDefinitions::default(),
+            style: egui::Style::default(),
+        }
+    }
+}
+
+/// The per-window input state and egui context, so a single [`Platform`] can
+/// drive several SDL2 windows at once
+struct WindowState {
+    // The position of the mouse pointer, in points
     pointer_pos: Pos2,
     // The egui modifiers
     modifiers: Modifiers,
     // The raw input
-    pub raw_input: egui::RawInput,
+    raw_input: egui::RawInput,
+
+    // The screen rect, in raw (unscaled) pixels, as last given to us
+    screen_rect_px: egui::Rect,
+    // The active scale factor, i.e. how many raw pixels make up one point
+    scale_factor: f32,
 
     compositing: bool,
     has_sent_ime_enabled: bool,
 
+    // The egui context
+    egui_ctx: egui::Context,
+}
+
+impl WindowState {
+    fn new(rect: egui::Rect, scale_factor: f32) -> Self {
+        let mut state = Self {
+            pointer_pos: Pos2::ZERO,
+            modifiers: Modifiers::default(),
+            raw_input: egui::RawInput::default(),
+
+            screen_rect_px: rect,
+            scale_factor,
+
+            compositing: false,
+            has_sent_ime_enabled: false,
+
+            egui_ctx: egui::Context::default(),
+        };
+
+        state.egui_ctx.set_pixels_per_point(scale_factor);
+        state.raw_input.screen_rect = Some(state.scaled_screen_rect());
+
+        #[cfg(feature = "accesskit")]
+        state.egui_ctx.enable_accesskit();
+
+        state
+    }
+
+    fn scaled_screen_rect(&self) -> egui::Rect {
+        egui::Rect::from_min_max(
+            self.screen_rect_px.min / self.scale_factor,
+            self.screen_rect_px.max / self.scale_factor,
+        )
+    }
+
+    fn change_target(&mut self, rect: egui::Rect) {
+        self.screen_rect_px = rect;
+        self.raw_input.screen_rect = Some(self.scaled_screen_rect());
+    }
+
+    fn ime_event_enable(&mut self) {
+        if !self.has_sent_ime_enabled {
+            self.raw_input
+                .events
+                .push(egui::Event::Ime(egui::ImeEvent::Enabled));
+            self.has_sent_ime_enabled = true;
+        }
+    }
+
+    fn ime_event_disable(&mut self) {
+        self.raw_input
+            .events
+            .push(egui::Event::Ime(egui::ImeEvent::Disabled));
+        self.has_sent_ime_enabled = false;
+    }
+}
+
+/// The sdl2 platform for egui
+pub struct Platform {
+    // The cursors for the platform, shared across all windows
+    cursor: Option<Cursor>,
+    system_cursor: SystemCursor,
+
+    // Per-window input state and egui context
+    windows: HashMap<WindowId, WindowState>,
+
     #[cfg(feature = "arboard")]
     clipboard: Clipboard,
-
-    // The egui context
-    pub egui_ctx: egui::Context,
 }
 
 impl Platform {
-    /// Construct a new [`Platform`]
+    /// Construct a new [`Platform`] with [`DpiScaling::Default`]
     pub fn new(screen_size: (u32, u32)) -> anyhow::Result<Self> {
-        Self::targeting(egui::Rect::from_min_size(
-            egui::Pos2::ZERO,
-            egui::Vec2 {
-                x: screen_size.0 as f32,
-                y: screen_size.1 as f32,
-            },
-        ))
+        Self::new_scaled(screen_size, DpiScaling::Default)
+    }
+
+    /// Construct a new [`Platform`] with an explicit [`DpiScaling`]
+    pub fn new_scaled(screen_size: (u32, u32), scaling: DpiScaling) -> anyhow::Result<Self> {
+        Self::targeting_scaled(
+            egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::Vec2 {
+                    x: screen_size.0 as f32,
+                    y: screen_size.1 as f32,
+                },
+            ),
+            scaling,
+        )
+    }
+
+    /// Construct a new [`Platform`] from an sdl2 window, auto-detecting the
+    /// scale factor from the ratio between its drawable size and its size.
+    pub fn from_window(window: &sdl2::video::Window) -> anyhow::Result<Self> {
+        let (w, h) = window.size();
+        let (drawable_w, _drawable_h) = window.drawable_size();
+        let scale_factor = if w == 0 {
+            1.0
+        } else {
+            drawable_w as f32 / w as f32
+        };
+        Self::new_scaled((w, h), DpiScaling::Custom(scale_factor))
     }
 
     pub fn targeting(rect: egui::Rect) -> anyhow::Result<Self> {
-        Ok(Self {
+        Self::targeting_scaled(rect, DpiScaling::Default)
+    }
+
+    /// Construct a new [`Platform`] targeting `rect` (in raw pixels) with an
+    /// explicit [`DpiScaling`]
+    pub fn targeting_scaled(rect: egui::Rect, scaling: DpiScaling) -> anyhow::Result<Self> {
+        let mut platform = Self {
             cursor: Cursor::from_system(SystemCursor::Arrow)
                 .map_err(|e| log::warn!("Failed to get cursor from systems cursor: {}", e))
                 .ok(),
             system_cursor: SystemCursor::Arrow,
-            pointer_pos: Pos2::ZERO,
-            raw_input: egui::RawInput {
-                screen_rect: Some(rect),
-                ..Default::default()
-            },
 
-            compositing: false,
-            has_sent_ime_enabled: false,
+            windows: HashMap::new(),
 
             #[cfg(feature = "arboard")]
             clipboard: Clipboard::new()?,
+        };
 
-            modifiers: Modifiers::default(),
-            egui_ctx: egui::Context::default(),
+        platform.add_window(DEFAULT_WINDOW_ID, rect, scaling);
+
+        Ok(platform)
+    }
+
+    /// Construct a new [`Platform`] from a [`PlatformDescriptor`], applying
+    /// its fonts, style, and scale factor to the default window before the
+    /// first frame is drawn.
+    pub fn with_descriptor(desc: PlatformDescriptor) -> anyhow::Result<Self> {
+        let mut platform =
+            Self::targeting_scaled(desc.screen_rect, DpiScaling::Custom(desc.scale_factor))?;
+
+        let egui_ctx = platform.egui_ctx();
+        egui_ctx.set_fonts(desc.font_definitions);
+        egui_ctx.set_style(desc.style);
+
+        Ok(platform)
+    }
+
+    /// Register a new window with this [`Platform`], so that events carrying
+    /// its [`WindowId`] are routed to their own input state and egui context.
+    ///
+    /// Events carrying a [`WindowId`] that hasn't been registered this way
+    /// fall back to the default window's input state and egui context,
+    /// rather than being dropped or given a state of their own; call this
+    /// up front for every additional window you want driven separately.
+    pub fn add_window(&mut self, window_id: WindowId, rect: egui::Rect, scaling: DpiScaling) {
+        let scale_factor = match scaling {
+            DpiScaling::Default => 1.0,
+            DpiScaling::Custom(factor) => factor,
+        };
+
+        self.windows
+            .insert(window_id, WindowState::new(rect, scale_factor));
+    }
+
+    /// Drop a window's input state and egui context, e.g. once it's closed
+    pub fn remove_window(&mut self, window_id: WindowId) {
+        self.windows.remove(&window_id);
+    }
+
+    fn window_state_mut(&mut self, window_id: WindowId) -> &mut WindowState {
+        // Events for a window id that was never registered via `add_window`
+        // fall back to the default window's state, instead of lazily
+        // spawning a phantom, never-rendered window of their own. This is
+        // what makes the single-window API work: `new()`/`targeting()` only
+        // ever register `DEFAULT_WINDOW_ID`, so every real SDL window id
+        // (which always starts at 1) routes straight back to it.
+        let window_id = if self.windows.contains_key(&window_id) {
+            window_id
+        } else {
+            DEFAULT_WINDOW_ID
+        };
+
+        self.windows.entry(window_id).or_insert_with(|| {
+            WindowState::new(
+                egui::Rect::from_min_size(egui::Pos2::ZERO, egui::Vec2::ZERO),
+                1.0,
+            )
         })
     }
 
+    /// The current scale factor for the default window, i.e. how many raw
+    /// pixels make up one point
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor_for(DEFAULT_WINDOW_ID)
+    }
+
+    /// The current scale factor for `window_id`, i.e. how many raw pixels
+    /// make up one point
+    pub fn scale_factor_for(&self, window_id: WindowId) -> f32 {
+        self.windows
+            .get(&window_id)
+            .map_or(1.0, |window| window.scale_factor)
+    }
+
+    /// Resize the default window's target rect (in raw pixels)
     pub fn change_target(&mut self, rect: egui::Rect) {
-        self.raw_input.screen_rect = Some(rect);
+        self.change_target_for(DEFAULT_WINDOW_ID, rect);
+    }
+
+    /// Resize `window_id`'s target rect (in raw pixels)
+    pub fn change_target_for(&mut self, window_id: WindowId, rect: egui::Rect) {
+        self.window_state_mut(window_id).change_target(rect);
+    }
+
+    /// The raw input for the default window, ready to be inspected or
+    /// mutated before the next [`Platform::context`] call
+    pub fn raw_input(&mut self) -> &mut egui::RawInput {
+        &mut self.window_state_mut(DEFAULT_WINDOW_ID).raw_input
+    }
+
+    /// The raw input for `window_id`, ready to be inspected or mutated
+    /// before the next [`Platform::context_for`] call
+    pub fn raw_input_for(&mut self, window_id: WindowId) -> &mut egui::RawInput {
+        &mut self.window_state_mut(window_id).raw_input
+    }
+
+    /// The egui context driving the default window
+    pub fn egui_ctx(&mut self) -> &egui::Context {
+        &self.window_state_mut(DEFAULT_WINDOW_ID).egui_ctx
+    }
+
+    /// The egui context driving `window_id`
+    pub fn egui_ctx_for(&mut self, window_id: WindowId) -> &egui::Context {
+        &self.window_state_mut(window_id).egui_ctx
     }
 
     /// Handle a sdl2 event
-    pub fn handle_event(&mut self, event: &Event) {
+    ///
+    /// Returns an [`EventResponse`] that tells the caller whether egui
+    /// consumed the event, so input can be routed to the rest of the
+    /// application (camera controls, game input, etc.) when it wasn't.
+    pub fn handle_event(&mut self, event: &Event) -> EventResponse {
         match event {
             // Handle reizing
-            Event::Window { win_event, .. } => match win_event {
+            Event::Window {
+                win_event,
+                window_id,
+                ..
+            } => match win_event {
                 WindowEvent::Resized(w, h) | WindowEvent::SizeChanged(w, h) => {
-                    self.change_target(egui::Rect::from_min_size(
+                    let rect = egui::Rect::from_min_size(
                         egui::Pos2::ZERO,
                         egui::Vec2 {
                             x: *w as f32,
                             y: *h as f32,
                         },
-                    ));
+                    );
+                    let resized = self.window_state_mut(*window_id).screen_rect_px != rect;
+                    self.change_target_for(*window_id, rect);
+                    EventResponse {
+                        consumed: false,
+                        repaint: resized,
+                    }
                 }
-                _ => {}
+                // Losing focus (e.g. alt-tab) shouldn't leave stale modifiers
+                // or a stuck hover highlight behind
+                WindowEvent::FocusLost => {
+                    let window = self.window_state_mut(*window_id);
+                    window.raw_input.focused = false;
+                    window.modifiers = Modifiers::default();
+                    window.raw_input.modifiers = window.modifiers;
+                    window.raw_input.events.push(egui::Event::PointerGone);
+                    EventResponse {
+                        consumed: false,
+                        repaint: true,
+                    }
+                }
+                WindowEvent::FocusGained => {
+                    self.window_state_mut(*window_id).raw_input.focused = true;
+                    EventResponse {
+                        consumed: false,
+                        repaint: true,
+                    }
+                }
+                // The cursor leaving/re-entering the window
+                WindowEvent::Leave => {
+                    self.window_state_mut(*window_id)
+                        .raw_input
+                        .events
+                        .push(egui::Event::PointerGone);
+                    EventResponse {
+                        consumed: false,
+                        repaint: true,
+                    }
+                }
+                WindowEvent::Enter => {
+                    let window = self.window_state_mut(*window_id);
+                    let pointer_pos = window.pointer_pos;
+                    window
+                        .raw_input
+                        .events
+                        .push(egui::Event::PointerMoved(pointer_pos));
+                    EventResponse {
+                        consumed: false,
+                        repaint: true,
+                    }
+                }
+                _ => EventResponse::default(),
             },
             // Handle the mouse button being held down
-            Event::MouseButtonDown { mouse_btn, .. } => {
+            Event::MouseButtonDown {
+                mouse_btn,
+                window_id,
+                ..
+            } => {
                 let btn = match mouse_btn {
                     MouseButton::Left => Some(egui::PointerButton::Primary),
                     MouseButton::Middle => Some(egui::PointerButton::Middle),
                     MouseButton::Right => Some(egui::PointerButton::Secondary),
                     _ => None,
                 };
+                let window = self.window_state_mut(*window_id);
                 if let Some(btn) = btn {
-                    self.raw_input.events.push(egui::Event::PointerButton {
-                        pos: self.pointer_pos,
+                    window.raw_input.events.push(egui::Event::PointerButton {
+                        pos: window.pointer_pos,
                         button: btn,
                         pressed: true,
-                        modifiers: self.modifiers,
+                        modifiers: window.modifiers,
                     });
                 }
-                self.egui_ctx.wants_pointer_input();
+                EventResponse {
+                    consumed: window.egui_ctx.wants_pointer_input(),
+                    // Unmapped buttons (e.g. X1/X2) never produced an event
+                    repaint: btn.is_some(),
+                }
             }
             // Handle the mouse button being released
-            Event::MouseButtonUp { mouse_btn, .. } => {
+            Event::MouseButtonUp {
+                mouse_btn,
+                window_id,
+                ..
+            } => {
                 let btn = match mouse_btn {
                     MouseButton::Left => Some(egui::PointerButton::Primary),
                     MouseButton::Middle => Some(egui::PointerButton::Middle),
                     MouseButton::Right => Some(egui::PointerButton::Secondary),
                     _ => None,
                 };
+                let window = self.window_state_mut(*window_id);
                 if let Some(btn) = btn {
-                    self.raw_input.events.push(egui::Event::PointerButton {
-                        pos: self.pointer_pos,
+                    window.raw_input.events.push(egui::Event::PointerButton {
+                        pos: window.pointer_pos,
                         button: btn,
                         pressed: false,
-                        modifiers: self.modifiers,
+                        modifiers: window.modifiers,
                     });
                 }
-                self.egui_ctx.wants_pointer_input();
+                EventResponse {
+                    consumed: window.egui_ctx.wants_pointer_input(),
+                    // Unmapped buttons (e.g. X1/X2) never produced an event
+                    repaint: btn.is_some(),
+                }
             }
             // Handle mouse motion
-            Event::MouseMotion { x, y, .. } => {
-                // Update the pointer position
-                self.pointer_pos = egui::Pos2::new(*x as f32, *y as f32);
-                self.raw_input
-                    .events
-                    .push(egui::Event::PointerMoved(self.pointer_pos));
-                self.egui_ctx.wants_pointer_input();
+            Event::MouseMotion {
+                x, y, window_id, ..
+            } => {
+                let window = self.window_state_mut(*window_id);
+                // Update the pointer position, converting from raw pixels to points
+                let pointer_pos = egui::Pos2::new(*x as f32, *y as f32) / window.scale_factor;
+                let moved = window.pointer_pos != pointer_pos;
+                window.pointer_pos = pointer_pos;
+                if moved {
+                    window
+                        .raw_input
+                        .events
+                        .push(egui::Event::PointerMoved(pointer_pos));
+                }
+                EventResponse {
+                    consumed: window.egui_ctx.wants_pointer_input(),
+                    repaint: moved,
+                }
             }
             // Handle the mouse scrolling
-            Event::MouseWheel { x, y, .. } => {
-                // Calculate the delta
-                let delta = egui::Vec2::new(*x as f32 * 8.0, *y as f32 * 8.0);
-                self.raw_input.events.push(egui::Event::MouseWheel {
-                    delta,
-                    unit: egui::MouseWheelUnit::Point,
-                    modifiers: self.modifiers,
-                });
-                self.egui_ctx.wants_pointer_input();
+            Event::MouseWheel {
+                x, y, window_id, ..
+            } => {
+                let window = self.window_state_mut(*window_id);
+                if window.modifiers.ctrl {
+                    // Ctrl-scroll zooms rather than scrolling
+                    window
+                        .raw_input
+                        .events
+                        .push(egui::Event::Zoom((*y as f32 * ZOOM_STEP).exp()));
+                } else {
+                    // Calculate the delta
+                    let delta = egui::Vec2::new(*x as f32 * 8.0, *y as f32 * 8.0);
+                    window.raw_input.events.push(egui::Event::MouseWheel {
+                        delta,
+                        unit: egui::MouseWheelUnit::Point,
+                        modifiers: window.modifiers,
+                    });
+                }
+                EventResponse {
+                    consumed: window.egui_ctx.wants_pointer_input(),
+                    repaint: true,
+                }
+            }
+            // Handle a single-finger touch starting, moving, or ending.
+            // SDL2 doesn't associate touch events with a window, so these
+            // are always routed to the default window, which `window_state_mut`
+            // guarantees is the one actually backing the live window rather
+            // than an unrendered phantom.
+            Event::FingerDown {
+                touch_id,
+                finger_id,
+                x,
+                y,
+                pressure,
+                ..
+            } => self.push_touch_event(
+                *touch_id,
+                *finger_id,
+                egui::TouchPhase::Start,
+                *x,
+                *y,
+                *pressure,
+            ),
+            Event::FingerMotion {
+                touch_id,
+                finger_id,
+                x,
+                y,
+                pressure,
+                ..
+            } => self.push_touch_event(
+                *touch_id,
+                *finger_id,
+                egui::TouchPhase::Move,
+                *x,
+                *y,
+                *pressure,
+            ),
+            Event::FingerUp {
+                touch_id,
+                finger_id,
+                x,
+                y,
+                pressure,
+                ..
+            } => self.push_touch_event(
+                *touch_id,
+                *finger_id,
+                egui::TouchPhase::End,
+                *x,
+                *y,
+                *pressure,
+            ),
+            // Handle pinch-to-zoom. Like touch events, SDL2 doesn't
+            // associate gestures with a window, so this always targets the
+            // default window's (live) input state.
+            Event::MultiGesture { d_dist, .. } => {
+                let window = self.window_state_mut(DEFAULT_WINDOW_ID);
+                window
+                    .raw_input
+                    .events
+                    .push(egui::Event::Zoom((*d_dist * ZOOM_STEP).exp()));
+                EventResponse {
+                    consumed: window.egui_ctx.wants_pointer_input(),
+                    repaint: true,
+                }
             }
             // Handle a key being pressed
             Event::KeyDown {
-                keycode, keymod, ..
+                keycode,
+                keymod,
+                window_id,
+                ..
             } => {
                 // Make sure there is a keycode
+                let mut key_pressed = false;
                 if let Some(keycode) = keycode {
                     // Convert the keycode to an egui key
                     if let Some(key) = keycode.to_egui_key() {
+                        key_pressed = true;
                         // Check the modifiers
                         use sdl2::keyboard::Mod;
                         let alt = (*keymod & Mod::LALTMOD == Mod::LALTMOD)
@@ -161,16 +589,25 @@ impl Platform {
                         let command = (*keymod & Mod::LCTRLMOD == Mod::LCTRLMOD)
                             || (*keymod & Mod::LGUIMOD == Mod::LGUIMOD);
 
-                        // Handle Cut Copy and paste manually
+                        // Handle Cut Copy and paste manually. The paste text
+                        // is fetched from the clipboard up front, since the
+                        // clipboard and the per-window state can't be
+                        // borrowed from `self` at the same time.
+                        #[cfg(feature = "arboard")]
+                        let pasted_text = (ctrl && key == egui::Key::V)
+                            .then(|| self.clipboard.get_text().ok())
+                            .flatten();
+
+                        let window = self.window_state_mut(*window_id);
 
                         if ctrl {
                             match key {
-                                egui::Key::C => self.raw_input.events.push(egui::Event::Copy),
-                                egui::Key::X => self.raw_input.events.push(egui::Event::Cut),
+                                egui::Key::C => window.raw_input.events.push(egui::Event::Copy),
+                                egui::Key::X => window.raw_input.events.push(egui::Event::Cut),
                                 #[cfg(feature = "arboard")]
                                 egui::Key::V => {
-                                    if let Ok(txt) = self.clipboard.get_text() {
-                                        self.raw_input.events.push(egui::Event::Paste(txt));
+                                    if let Some(txt) = pasted_text {
+                                        window.raw_input.events.push(egui::Event::Paste(txt));
                                     }
                                 }
                                 _ => {}
@@ -178,34 +615,47 @@ impl Platform {
                         }
 
                         // Update the modifiers
-                        self.modifiers = Modifiers {
+                        window.modifiers = Modifiers {
                             alt,
                             ctrl,
                             shift,
                             mac_cmd,
                             command,
                         };
-                        self.raw_input.modifiers = self.modifiers;
+                        window.raw_input.modifiers = window.modifiers;
                         // Push the event
-                        self.raw_input.events.push(egui::Event::Key {
+                        window.raw_input.events.push(egui::Event::Key {
                             key,
                             physical_key: Some(key),
                             pressed: true,
                             repeat: false,
-                            modifiers: self.modifiers,
+                            modifiers: window.modifiers,
                         });
                     }
                 }
-                self.egui_ctx.wants_keyboard_input();
+                EventResponse {
+                    consumed: self
+                        .window_state_mut(*window_id)
+                        .egui_ctx
+                        .wants_keyboard_input(),
+                    // Unrecognized keycodes never produced an event
+                    repaint: key_pressed,
+                }
             }
             // Handle a key being released
             Event::KeyUp {
-                keycode, keymod, ..
+                keycode,
+                keymod,
+                window_id,
+                ..
             } => {
+                let window = self.window_state_mut(*window_id);
                 // Make sure there is a keycode
+                let mut key_released = false;
                 if let Some(keycode) = keycode {
                     // Convert the keycode to an egui key
                     if let Some(key) = keycode.to_egui_key() {
+                        key_released = true;
                         // Check the modifiers
                         use sdl2::keyboard::Mod;
                         let alt = (*keymod & Mod::LALTMOD == Mod::LALTMOD)
@@ -219,96 +669,173 @@ impl Platform {
                             || (*keymod & Mod::LGUIMOD == Mod::LGUIMOD);
 
                         // Update the modifiers
-                        self.modifiers = Modifiers {
+                        window.modifiers = Modifiers {
                             alt,
                             ctrl,
                             shift,
                             mac_cmd,
                             command,
                         };
-                        self.raw_input.modifiers = self.modifiers;
+                        window.raw_input.modifiers = window.modifiers;
                         // Push the event
-                        self.raw_input.events.push(egui::Event::Key {
+                        window.raw_input.events.push(egui::Event::Key {
                             key,
                             physical_key: Some(key),
                             pressed: false,
                             repeat: false,
-                            modifiers: self.modifiers,
+                            modifiers: window.modifiers,
                         });
                     }
                 }
-                self.egui_ctx.wants_keyboard_input();
+                EventResponse {
+                    consumed: window.egui_ctx.wants_keyboard_input(),
+                    // Unrecognized keycodes never produced an event
+                    repaint: key_released,
+                }
             }
             // Handle text input
-            Event::TextInput { text, .. } => {
-                if std::mem::take(&mut self.compositing) {
-                    self.raw_input
+            Event::TextInput {
+                text, window_id, ..
+            } => {
+                let window = self.window_state_mut(*window_id);
+                if std::mem::take(&mut window.compositing) {
+                    window
+                        .raw_input
                         .events
                         .push(egui::Event::Ime(egui::ImeEvent::Commit(text.clone())));
-                    self.ime_event_disable(); // Windows?
+                    window.ime_event_disable(); // Windows?
                 } else {
-                    self.raw_input.events.push(egui::Event::Text(text.clone()));
+                    window
+                        .raw_input
+                        .events
+                        .push(egui::Event::Text(text.clone()));
+                }
+                EventResponse {
+                    consumed: window.egui_ctx.wants_keyboard_input(),
+                    repaint: true,
                 }
-                self.egui_ctx.wants_keyboard_input();
             }
             Event::TextEditing {
                 text,
                 start,
                 length,
+                window_id,
                 ..
             } => {
+                let window = self.window_state_mut(*window_id);
                 if (*start == 0 && *length == 0) || text.is_empty() {
-                    self.ime_event_disable(); // Linux?
+                    window.ime_event_disable(); // Linux?
                 } else {
-                    self.ime_event_enable();
-                    self.compositing = true;
-                    self.raw_input
+                    window.ime_event_enable();
+                    window.compositing = true;
+                    window
+                        .raw_input
                         .events
                         .push(egui::Event::Ime(egui::ImeEvent::Preedit(text.clone())));
                 }
-                self.egui_ctx.wants_keyboard_input();
+                EventResponse {
+                    consumed: window.egui_ctx.wants_keyboard_input(),
+                    repaint: true,
+                }
             }
-            _ => {}
+            _ => EventResponse::default(),
         }
     }
 
-    fn ime_event_enable(&mut self) {
-        if !self.has_sent_ime_enabled {
-            self.raw_input
-                .events
-                .push(egui::Event::Ime(egui::ImeEvent::Enabled));
-            self.has_sent_ime_enabled = true;
+    /// Translate a normalized (0..1) SDL finger touch on the default window
+    /// into an [`egui::Event::Touch`] and push it to the queue
+    fn push_touch_event(
+        &mut self,
+        touch_id: i64,
+        finger_id: i64,
+        phase: egui::TouchPhase,
+        x: f32,
+        y: f32,
+        pressure: f32,
+    ) -> EventResponse {
+        let window = self.window_state_mut(DEFAULT_WINDOW_ID);
+        // `raw_input.screen_rect` is taken (left `None`) by `context_for`
+        // every frame, so it can't be used as a size source here; read the
+        // persistent `screen_rect_px`/`scale_factor` instead.
+        let screen_size = window.scaled_screen_rect().size();
+        let pos = egui::Pos2::new(x * screen_size.x, y * screen_size.y);
+        window.raw_input.events.push(egui::Event::Touch {
+            device_id: egui::TouchDeviceId(touch_id as u64),
+            id: egui::TouchId(finger_id as u64),
+            phase,
+            pos,
+            force: Some(pressure),
+        });
+        EventResponse {
+            consumed: window.egui_ctx.wants_pointer_input(),
+            repaint: true,
         }
     }
 
-    fn ime_event_disable(&mut self) {
-        self.raw_input
-            .events
-            .push(egui::Event::Ime(egui::ImeEvent::Disabled));
-        self.has_sent_ime_enabled = false;
-    }
-
-    /// Set the pixels per point
+    /// Set the pixels per point for the default window
     pub fn set_pixels_per_point(&mut self, pixels_per_point: f32) {
         self.context().set_pixels_per_point(pixels_per_point);
     }
 
-    /// Update the time
+    /// Update the time for every window
     pub fn update_time(&mut self, duration: f64) {
-        self.raw_input.time = Some(duration);
+        for window in self.windows.values_mut() {
+            window.raw_input.time = Some(duration);
+        }
     }
 
-    /// Return the processed context
+    /// Return the processed context for the default window
     pub fn context(&mut self) -> egui::Context {
+        self.context_for(DEFAULT_WINDOW_ID)
+    }
+
+    /// Return the processed context for `window_id`
+    pub fn context_for(&mut self, window_id: WindowId) -> egui::Context {
+        let window = self.window_state_mut(window_id);
         // Begin the frame
-        self.egui_ctx.begin_pass(self.raw_input.take());
+        window.egui_ctx.begin_pass(window.raw_input.take());
         // Return the ctx
-        self.egui_ctx.clone()
+        window.egui_ctx.clone()
     }
 
-    /// Stop drawing the egui frame and return the full output
+    /// Stop drawing the default window's egui frame and return the full output
     pub fn end_frame(&mut self) -> egui::FullOutput {
-        self.egui_ctx.end_pass()
+        self.end_frame_for(DEFAULT_WINDOW_ID)
+    }
+
+    /// Stop drawing `window_id`'s egui frame and return the full output
+    pub fn end_frame_for(&mut self, window_id: WindowId) -> egui::FullOutput {
+        self.window_state_mut(window_id).egui_ctx.end_pass()
+    }
+
+    /// Take the accessibility tree update out of a [`egui::FullOutput`], if
+    /// one was produced this frame.
+    ///
+    /// `egui_ctx.enable_accesskit()` was called for you when this [`Platform`]
+    /// was constructed, so `full_output.platform_output.accesskit_update`
+    /// is populated automatically; feed the returned tree to whichever
+    /// accesskit adapter is tied to your SDL2 window.
+    #[cfg(feature = "accesskit")]
+    pub fn accesskit_update(
+        &self,
+        full_output: &mut egui::FullOutput,
+    ) -> Option<accesskit::TreeUpdate> {
+        full_output.platform_output.accesskit_update.take()
+    }
+
+    /// Feed an [`accesskit::ActionRequest`] (as delivered by your window's
+    /// accesskit adapter) into egui, so controls activated by assistive
+    /// technology behave as if they were clicked/typed directly.
+    #[cfg(feature = "accesskit")]
+    pub fn push_accesskit_action_request(
+        &mut self,
+        window_id: WindowId,
+        request: accesskit::ActionRequest,
+    ) {
+        self.window_state_mut(window_id)
+            .raw_input
+            .events
+            .push(egui::Event::AccessKitActionRequest(request));
     }
 
     #[cfg(feature = "platform_ext")]
@@ -369,9 +896,22 @@ impl Platform {
         Ok(())
     }
 
-    /// Tessellate the egui frame
+    /// Tessellate the default window's egui frame
     pub fn tessellate(&self, shapes: Vec<epaint::ClippedShape>) -> Vec<egui::ClippedPrimitive> {
-        self.egui_ctx
-            .tessellate(shapes, self.egui_ctx.pixels_per_point())
+        self.tessellate_for(DEFAULT_WINDOW_ID, shapes)
+    }
+
+    /// Tessellate `window_id`'s egui frame
+    pub fn tessellate_for(
+        &self,
+        window_id: WindowId,
+        shapes: Vec<epaint::ClippedShape>,
+    ) -> Vec<egui::ClippedPrimitive> {
+        let Some(window) = self.windows.get(&window_id) else {
+            return Vec::new();
+        };
+        window
+            .egui_ctx
+            .tessellate(shapes, window.egui_ctx.pixels_per_point())
     }
 }